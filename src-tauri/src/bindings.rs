@@ -0,0 +1,30 @@
+//! Typed TypeScript bindings for this crate's `#[tauri::command]`s, generated
+//! via `specta`/`tauri-specta` so the frontend never hand-writes `invoke`
+//! signatures.
+
+use tauri_specta::{collect_commands, Builder};
+
+/// Collects every `#[specta::specta]`-annotated command into a single
+/// `tauri-specta` builder, used both to build the `invoke_handler` and to
+/// export `bindings.ts`.
+pub fn builder() -> Builder {
+    Builder::<tauri::Wry>::new().commands(collect_commands![
+        crate::greet,
+        crate::launcher::toggle_launcher_window,
+        crate::windows::open_window,
+        crate::windows::close_window
+    ])
+}
+
+/// Writes `bindings.ts` into the frontend source tree.
+///
+/// Only runs in debug builds behind the `specta-export` feature, so release
+/// builds and CI never depend on a writable frontend directory.
+#[cfg(all(debug_assertions, feature = "specta-export"))]
+pub fn export(builder: &Builder) {
+    use specta_typescript::Typescript;
+
+    builder
+        .export(Typescript::default(), "../src/bindings.ts")
+        .expect("failed to export typescript bindings");
+}
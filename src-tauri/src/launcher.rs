@@ -0,0 +1,90 @@
+//! Spotlight-style launcher window toggled by a global shortcut.
+
+use tauri::{AppHandle, Manager, Runtime, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+use tauri_plugin_global_shortcut::{
+    Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState,
+};
+
+const LAUNCHER_LABEL: &str = "launcher";
+const LAUNCHER_WIDTH: f64 = 640.0;
+const LAUNCHER_HEIGHT: f64 = 80.0;
+
+/// Registers the global shortcut (Cmd/Ctrl+Space) that toggles the launcher
+/// window, creating it lazily on first use.
+pub fn init<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    #[cfg(target_os = "macos")]
+    let modifiers = Modifiers::SUPER;
+    #[cfg(not(target_os = "macos"))]
+    let modifiers = Modifiers::CONTROL;
+
+    let shortcut = Shortcut::new(Some(modifiers), Code::Space);
+
+    app.handle().plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(move |app, triggered, event| {
+                if *triggered == shortcut && event.state() == ShortcutState::Pressed {
+                    toggle_launcher(app);
+                }
+            })
+            .build(),
+    )?;
+
+    app.global_shortcut().register(shortcut)?;
+
+    Ok(())
+}
+
+/// Shows the launcher (creating it if needed) or hides it if already visible.
+#[tauri::command]
+#[specta::specta]
+pub fn toggle_launcher_window<R: Runtime>(app: AppHandle<R>) -> tauri::Result<()> {
+    toggle_launcher(&app);
+    Ok(())
+}
+
+fn toggle_launcher<R: Runtime>(app: &AppHandle<R>) {
+    match app.get_webview_window(LAUNCHER_LABEL) {
+        Some(window) if window.is_visible().unwrap_or(false) => {
+            let _ = window.hide();
+        }
+        Some(window) => show_launcher(&window),
+        None => match create_launcher_window(app) {
+            Ok(window) => show_launcher(&window),
+            Err(err) => log::error!("Failed to create launcher window: {err}"),
+        },
+    }
+}
+
+fn show_launcher<R: Runtime>(window: &tauri::WebviewWindow<R>) {
+    let _ = window.center();
+    let _ = window.show();
+    let _ = window.set_focus();
+}
+
+fn create_launcher_window<R: Runtime>(
+    app: &AppHandle<R>,
+) -> tauri::Result<tauri::WebviewWindow<R>> {
+    let window = WebviewWindowBuilder::new(app, LAUNCHER_LABEL, WebviewUrl::App("launcher".into()))
+        .title("Launcher")
+        .inner_size(LAUNCHER_WIDTH, LAUNCHER_HEIGHT)
+        .decorations(false)
+        .always_on_top(true)
+        .center()
+        .skip_taskbar(true)
+        .visible(false)
+        .build()?;
+
+    #[cfg(target_os = "macos")]
+    crate::macos::configure_launcher_window(&window)?;
+
+    // Losing focus hides the launcher the same way pressing the shortcut
+    // again does.
+    let window_clone = window.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Focused(false) = event {
+            let _ = window_clone.hide();
+        }
+    });
+
+    Ok(window)
+}
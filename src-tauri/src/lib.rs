@@ -2,9 +2,20 @@
 
 #[cfg(target_os = "macos")]
 mod macos;
+mod bindings;
+mod launcher;
+mod menu;
+mod tray;
+mod windows;
+
+/// Shared tabbing identifier so windows spawned by this app group into the
+/// same macOS tabbed window.
+#[cfg(target_os = "macos")]
+pub(crate) const WINDOW_TABBING_IDENTIFIER: &str = "dev.tauri-solidjs-template.tabbing";
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
+#[specta::specta]
 fn greet(name: &str) -> String {
     format!("Hello, {name}! You've been greeted from Rust!")
 }
@@ -16,18 +27,44 @@ fn greet(name: &str) -> String {
 /// Panics if the application fails to initialize or run.
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let specta_builder = bindings::builder();
+
+    #[cfg(all(debug_assertions, feature = "specta-export"))]
+    bindings::export(&specta_builder);
+
+    let app = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet])
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .invoke_handler(specta_builder.invoke_handler())
         .setup(|app| {
+            menu::build_default_menu(app.handle())?;
+            tray::init(app.handle())?;
+            launcher::init(app.handle())?;
+            windows::init(app.handle())?;
+
             #[cfg(target_os = "macos")]
             {
+                use tauri::Manager;
+
+                if let Some(window) = app.get_webview_window("main") {
+                    macos::configure_window_tabbing(&window, WINDOW_TABBING_IDENTIFIER, true)?;
+                }
+
                 app.run_on_main_thread(move || {
                     macos::configure_window_menu();
                 })?;
             }
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
+
+    app.run(|app_handle, event| {
+        // The tray's "Quit" path exits without closing the main window (the
+        // minimize-to-tray close handler only hides it), so geometry wouldn't
+        // otherwise get persisted for it on the way out; catch that here.
+        if let tauri::RunEvent::ExitRequested { .. } = event {
+            windows::save_all_geometry(app_handle);
+        }
+    });
 }
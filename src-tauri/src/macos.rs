@@ -28,3 +28,97 @@ pub fn configure_window_menu() {
         }
     }
 }
+
+/// Sets the text shown next to a tray icon's image via the status item's
+/// button, the same way title text is set on any `NSStatusItem`.
+///
+/// `status_item` is the tray's underlying `NSStatusItem` pointer; no-op if
+/// `None`.
+pub fn set_tray_title(status_item: Option<*mut std::ffi::c_void>, title: &str) {
+    unsafe {
+        use objc2::{msg_send, runtime::AnyObject};
+        use objc2_foundation::NSString;
+
+        let Some(status_item) = status_item else {
+            return;
+        };
+        let status_item = status_item as *mut AnyObject;
+
+        let button: *mut AnyObject = msg_send![status_item, button];
+        if button.is_null() {
+            log::warn!("Tray status item has no button, cannot set title");
+            return;
+        }
+
+        let ns_title = NSString::from_str(title);
+        let _: () = msg_send![button, setTitle: &*ns_title];
+    }
+}
+
+/// `NSWindowTabbingMode` values used by [`configure_window_tabbing`], mirrored
+/// from AppKit. `Preferred` is omitted since this module only ever toggles
+/// tabbing on (`Automatic`) or off (`Disallowed`).
+#[repr(isize)]
+enum NSWindowTabbingMode {
+    Automatic = 0,
+    Disallowed = 2,
+}
+
+/// Enables (or disables) native macOS window tabbing for `window` and sets
+/// its tabbing identifier, so windows created with the same identifier group
+/// into a single tabbed window via the OS-provided "Show Tab Bar" and
+/// "Merge All Windows" behaviors.
+///
+/// `enabled` has no default: this crate's callers pass `true`; pass `false` to
+/// opt a window out.
+pub fn configure_window_tabbing<R: tauri::Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    identifier: &str,
+    enabled: bool,
+) -> tauri::Result<()> {
+    let ns_window = window.ns_window()?;
+
+    unsafe {
+        use objc2::{msg_send, runtime::AnyObject};
+        use objc2_foundation::NSString;
+
+        let ns_window = ns_window as *mut AnyObject;
+
+        let mode = if enabled {
+            NSWindowTabbingMode::Automatic
+        } else {
+            NSWindowTabbingMode::Disallowed
+        };
+        let _: () = msg_send![ns_window, setTabbingMode: mode as isize];
+
+        let ns_identifier = NSString::from_str(identifier);
+        let _: () = msg_send![ns_window, setTabbingIdentifier: &*ns_identifier];
+    }
+
+    Ok(())
+}
+
+/// `NSWindowCollectionBehavior` flags used by [`configure_launcher_window`].
+const NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES: isize = 1 << 0;
+const NS_WINDOW_COLLECTION_BEHAVIOR_FULL_SCREEN_AUXILIARY: isize = 1 << 8;
+
+/// Lets the launcher overlay appear over fullscreen spaces by setting its
+/// collection behavior so it joins every Space as a full-screen auxiliary
+/// window. Only the collection behavior changes; activation does not, so
+/// showing and focusing the launcher activates the app like any other window.
+pub fn configure_launcher_window<R: tauri::Runtime>(
+    window: &tauri::WebviewWindow<R>,
+) -> tauri::Result<()> {
+    let ns_window = window.ns_window()?;
+
+    unsafe {
+        use objc2::{msg_send, runtime::AnyObject};
+
+        let ns_window = ns_window as *mut AnyObject;
+        let behavior = NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES
+            | NS_WINDOW_COLLECTION_BEHAVIOR_FULL_SCREEN_AUXILIARY;
+        let _: () = msg_send![ns_window, setCollectionBehavior: behavior];
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Cross-platform default application menu.
+
+use tauri::{
+    menu::{AboutMetadataBuilder, Menu, MenuBuilder, SubmenuBuilder},
+    AppHandle, Runtime,
+};
+
+/// Builds the conventional App / File / Edit / View / Window / Help submenu
+/// set and attaches it to the app.
+///
+/// On macOS this gives template users working Cmd+C/V/X, Cmd+Q, Cmd+W
+/// shortcuts out of the box; on Windows and Linux it installs a minimal
+/// menu bar so `tauri::Menu` is still populated but stays out of the way.
+///
+/// Call [`super::macos::configure_window_menu`] afterwards on macOS to enrich
+/// the generated "Window" submenu with tiling options.
+pub fn build_default_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
+    #[cfg(target_os = "macos")]
+    {
+        let app_menu = SubmenuBuilder::new(app, &app.package_info().name)
+            .about(Some(AboutMetadataBuilder::new().build()))
+            .separator()
+            .services()
+            .separator()
+            .hide()
+            .hide_others()
+            .show_all()
+            .separator()
+            .quit()
+            .build()?;
+
+        let file_menu = SubmenuBuilder::new(app, "File")
+            .close_window()
+            .build()?;
+
+        let edit_menu = build_edit_submenu(app)?;
+
+        let view_menu = SubmenuBuilder::new(app, "View")
+            .fullscreen()
+            .build()?;
+
+        let window_menu = SubmenuBuilder::new(app, "Window")
+            .minimize()
+            .maximize()
+            .separator()
+            .build()?;
+
+        let help_menu = SubmenuBuilder::new(app, "Help").build()?;
+
+        let menu = MenuBuilder::new(app)
+            .items(&[
+                &app_menu,
+                &file_menu,
+                &edit_menu,
+                &view_menu,
+                &window_menu,
+                &help_menu,
+            ])
+            .build()?;
+
+        app.set_menu(menu.clone())?;
+        return Ok(menu);
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let file_menu = SubmenuBuilder::new(app, "File").close_window().build()?;
+        let edit_menu = build_edit_submenu(app)?;
+
+        let menu = MenuBuilder::new(app)
+            .items(&[&file_menu, &edit_menu])
+            .build()?;
+
+        app.set_menu(menu.clone())?;
+        Ok(menu)
+    }
+}
+
+/// The standard Edit submenu, wired to Tauri's predefined items so copy,
+/// paste, cut, select-all and undo/redo work without custom handlers.
+fn build_edit_submenu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<tauri::menu::Submenu<R>> {
+    SubmenuBuilder::new(app, "Edit")
+        .undo()
+        .redo()
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .select_all()
+        .build()
+}
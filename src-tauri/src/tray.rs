@@ -0,0 +1,90 @@
+//! System tray icon with show/hide/quit and minimize-to-tray support.
+
+use tauri::{
+    menu::{MenuBuilder, MenuItemBuilder},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager, Runtime, WindowEvent,
+};
+
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Creates the tray icon, its Show/Hide/Quit context menu, and toggles the
+/// main window's visibility on left-click.
+///
+/// Also arms "minimize to tray": closing the main window hides it instead of
+/// exiting the app, and clicking the tray icon restores it.
+pub fn init<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let show = MenuItemBuilder::with_id("show", "Show").build(app)?;
+    let hide = MenuItemBuilder::with_id("hide", "Hide").build(app)?;
+    let quit = MenuItemBuilder::with_id("quit", "Quit").build(app)?;
+    let menu = MenuBuilder::new(app).items(&[&show, &hide, &quit]).build()?;
+
+    let mut builder = TrayIconBuilder::new();
+    match app.default_window_icon() {
+        Some(icon) => builder = builder.icon(icon.clone()),
+        None => log::warn!("No default window icon found; tray icon will have no image"),
+    }
+
+    builder
+        .menu(&menu)
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "show" => show_main_window(app),
+            "hide" => hide_main_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                let app = tray.app_handle();
+                match app.get_webview_window(MAIN_WINDOW_LABEL) {
+                    Some(window) if window.is_visible().unwrap_or(false) => hide_main_window(app),
+                    _ => show_main_window(app),
+                }
+            }
+        })
+        .build(app)?;
+
+    #[cfg(target_os = "macos")]
+    set_tray_title(app, &app.package_info().name);
+
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+        let window_clone = window.clone();
+        window.on_window_event(move |event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                api.prevent_close();
+                let _ = window_clone.hide();
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Sets the macOS-only text shown next to the tray icon.
+///
+/// Delegates the raw `setTitle:` message send to [`crate::macos::set_tray_title`],
+/// mirroring how `configure_window_menu` talks to AppKit directly.
+#[cfg(target_os = "macos")]
+pub fn set_tray_title<R: Runtime>(app: &AppHandle<R>, title: &str) {
+    for (_, tray) in app.tray_by_id_map() {
+        crate::macos::set_tray_title(tray.ns_status_item(), title);
+    }
+}
+
+fn show_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn hide_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+        let _ = window.hide();
+    }
+}
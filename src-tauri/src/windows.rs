@@ -0,0 +1,140 @@
+//! Multi-window management with window geometry persisted across restarts.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, Runtime, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "window-state.json";
+
+/// Persisted size, position and maximized state for a single window label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WindowGeometry {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+}
+
+/// Restores persisted geometry for every window already open (namely
+/// `"main"`) and arms geometry persistence for them. Windows opened later are
+/// not covered here; [`open_window`] arms those itself.
+pub fn init<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    for (_, window) in app.webview_windows() {
+        restore_geometry(&window);
+        track_geometry(window);
+    }
+    Ok(())
+}
+
+/// Opens a new named webview window, restoring its persisted geometry if
+/// this label has been opened (and closed) before.
+///
+/// On macOS the new window is folded into the existing tabbed window group
+/// and gets its own entry in the app's Window menu for free, since both are
+/// driven by AppKit once [`crate::macos::configure_window_tabbing`] and
+/// `setWindowsMenu:` have been applied once at startup.
+#[tauri::command]
+#[specta::specta]
+pub fn open_window<R: Runtime>(app: AppHandle<R>, label: String, url: String) -> tauri::Result<()> {
+    if let Some(window) = app.get_webview_window(&label) {
+        window.set_focus()?;
+        return Ok(());
+    }
+
+    let window = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(url.into())).build()?;
+
+    restore_geometry(&window);
+
+    #[cfg(target_os = "macos")]
+    crate::macos::configure_window_tabbing(&window, crate::WINDOW_TABBING_IDENTIFIER, true)?;
+
+    track_geometry(window);
+
+    Ok(())
+}
+
+/// Closes the window with the given label, if it exists.
+#[tauri::command]
+#[specta::specta]
+pub fn close_window<R: Runtime>(app: AppHandle<R>, label: String) -> tauri::Result<()> {
+    if let Some(window) = app.get_webview_window(&label) {
+        window.close()?;
+    }
+    Ok(())
+}
+
+/// Applies this window's persisted geometry from the store, if any.
+fn restore_geometry<R: Runtime>(window: &tauri::WebviewWindow<R>) {
+    let Some(geometry) = load_geometry(window.app_handle(), window.label()) else {
+        return;
+    };
+
+    let _ = window.set_position(PhysicalPosition::new(geometry.x, geometry.y));
+    let _ = window.set_size(PhysicalSize::new(geometry.width, geometry.height));
+    if geometry.maximized {
+        let _ = window.maximize();
+    }
+}
+
+/// Persists this window's geometry to the store on close, since saving on
+/// every `Moved`/`Resized` event would write to disk on each pixel of a
+/// drag/resize.
+///
+/// This alone misses the common "quit" path, where the main window is hidden
+/// (not closed, per the tray's minimize-to-tray handling) and the app exits
+/// via `app.exit()` without emitting `CloseRequested`. [`save_all_geometry`]
+/// covers that path from `RunEvent::ExitRequested`.
+fn track_geometry<R: Runtime>(window: tauri::WebviewWindow<R>) {
+    let label = window.label().to_string();
+    window.on_window_event(move |event| {
+        if let WindowEvent::CloseRequested { .. } = event {
+            save_geometry(&window, &label);
+        }
+    });
+}
+
+/// Persists the geometry of every open window. Called on `ExitRequested` so
+/// windows that are only ever hidden (e.g. the tray-managed main window) get
+/// their state saved on the way out.
+pub fn save_all_geometry<R: Runtime>(app: &AppHandle<R>) {
+    for (label, window) in app.webview_windows() {
+        save_geometry(&window, &label);
+    }
+}
+
+fn load_geometry<R: Runtime>(app: &AppHandle<R>, label: &str) -> Option<WindowGeometry> {
+    let store = app.store(STORE_FILE).ok()?;
+    let value = store.get(label)?;
+    serde_json::from_value(value).ok()
+}
+
+fn save_geometry<R: Runtime>(window: &tauri::WebviewWindow<R>, label: &str) {
+    // `restore_geometry` reapplies this via `set_size`, which sets the inner
+    // size, so pair it with `inner_size` here rather than `outer_size` (the
+    // frame-inclusive size) to avoid growing the window by the title bar's
+    // height on every restart.
+    let (Ok(position), Ok(size), Ok(maximized)) = (
+        window.outer_position(),
+        window.inner_size(),
+        window.is_maximized(),
+    ) else {
+        return;
+    };
+
+    let geometry = WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized,
+    };
+
+    let Ok(store) = window.app_handle().store(STORE_FILE) else {
+        return;
+    };
+    if let Ok(value) = serde_json::to_value(geometry) {
+        store.set(label, value);
+        let _ = store.save();
+    }
+}